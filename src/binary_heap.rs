@@ -0,0 +1,143 @@
+// Array-backed binary max-heap, modeled on `std::collections::BinaryHeap`.
+pub struct BinaryHeap<T: Ord> {
+    data: Vec<T>,
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new() -> Self {
+        BinaryHeap { data: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let value = self.data.pop();
+        if !self.data.is_empty() {
+            self.sift_down(0);
+        }
+        value
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.data[i] <= self.data[parent] {
+                break;
+            }
+            self.data.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.data.len();
+        loop {
+            let left = 2 * i + 1;
+            let right = 2 * i + 2;
+            let mut largest = i;
+            if left < len && self.data[left] > self.data[largest] {
+                largest = left;
+            }
+            if right < len && self.data[right] > self.data[largest] {
+                largest = right;
+            }
+            if largest == i {
+                break;
+            }
+            self.data.swap(i, largest);
+            i = largest;
+        }
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinaryHeap<T> {
+    fn from(data: Vec<T>) -> Self {
+        let mut heap = BinaryHeap { data };
+        // Heapify in O(n) by sifting down every non-leaf index, starting from the last one.
+        for i in (0..heap.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+        heap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works_push_pop_in_priority_order() {
+        let mut heap = BinaryHeap::new();
+        heap.push(3);
+        heap.push(1);
+        heap.push(4);
+        heap.push(1);
+        heap.push(5);
+
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), Some(4));
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn works_peek() {
+        let mut heap = BinaryHeap::new();
+        assert_eq!(heap.peek(), None);
+
+        heap.push(2);
+        heap.push(9);
+        heap.push(5);
+
+        assert_eq!(heap.peek(), Some(&9));
+        assert_eq!(heap.len(), 3);
+    }
+
+    #[test]
+    fn works_from_vec_heapify() {
+        let mut heap = BinaryHeap::from(vec![5, 3, 8, 1, 9, 2]);
+
+        let mut sorted_desc = Vec::new();
+        while let Some(value) = heap.pop() {
+            sorted_desc.push(value);
+        }
+
+        assert_eq!(sorted_desc, vec![9, 8, 5, 3, 2, 1]);
+    }
+
+    #[test]
+    fn works_is_empty() {
+        let mut heap: BinaryHeap<i32> = BinaryHeap::new();
+        assert!(heap.is_empty());
+        heap.push(1);
+        assert!(!heap.is_empty());
+    }
+}
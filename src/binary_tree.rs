@@ -7,6 +7,7 @@ struct Tree {
 #[derive(Debug)]
 struct Node {
     value: i32,
+    height: i32,
     left: Option<Box<Node>>,
     right: Option<Box<Node>>,
 }
@@ -15,6 +16,7 @@ impl Node {
     fn new(value: i32) -> Self {
         Node {
             value,
+            height: 1,
             left: None,
             right: None,
         }
@@ -33,14 +35,12 @@ impl Tree {
     }
 
     fn insert(&mut self, value: i32) {
-        match &mut self.root {
+        match self.root.take() {
             None => {
                 self.root = Node::new(value).into();
             },
             Some(node) => {
-                // can't self.insert_recursive because self is already mutable until the end of the match
-                Tree::insert_recursive(node, value);
-                // self.insert_iterative(value);
+                self.root = Some(Tree::insert_recursive(node, value));
             }
         }
     }
@@ -80,29 +80,166 @@ impl Tree {
         }
     }
 
-    fn insert_recursive(node: &mut Box<Node>, value: i32) {
+    fn insert_recursive(mut node: Box<Node>, value: i32) -> Box<Node> {
         if value > node.value {
-            match &mut node.right {
-                None => {
-                    node.right = Node::new(value).into();
-                },
-                Some(right) => {
-                    Self::insert_recursive(right, value);
-                }
-            }
+            node.right = Some(match node.right.take() {
+                None => Box::new(Node::new(value)),
+                Some(right) => Self::insert_recursive(right, value),
+            });
         } else if value < node.value {
-            match &mut node.left {
-                None => {
-                    node.left = Node::new(value).into();
-                },
-                Some(left) => {
-                    Self::insert_recursive(left, value);
-                }
+            node.left = Some(match node.left.take() {
+                None => Box::new(Node::new(value)),
+                Some(left) => Self::insert_recursive(left, value),
+            });
+        }
+        Self::rebalance(node)
+    }
+
+    fn height(node: &Option<Box<Node>>) -> i32 {
+        node.as_ref().map_or(0, |n| n.height)
+    }
+
+    fn update_height(node: &mut Node) {
+        node.height = 1 + Self::height(&node.left).max(Self::height(&node.right));
+    }
+
+    fn balance_factor(node: &Node) -> i32 {
+        Self::height(&node.left) - Self::height(&node.right)
+    }
+
+    // Pivots `node`'s left child up: that child's right subtree becomes `node`'s new left
+    // subtree, and `node` becomes the new root's right child.
+    fn rotate_right(mut node: Box<Node>) -> Box<Node> {
+        let mut left = node.left.take().expect("rotate_right requires a left child");
+        node.left = left.right.take();
+        Self::update_height(&mut node);
+        left.right = Some(node);
+        Self::update_height(&mut left);
+        left
+    }
+
+    // Mirror image of `rotate_right`.
+    fn rotate_left(mut node: Box<Node>) -> Box<Node> {
+        let mut right = node.right.take().expect("rotate_left requires a right child");
+        node.right = right.left.take();
+        Self::update_height(&mut node);
+        right.left = Some(node);
+        Self::update_height(&mut right);
+        right
+    }
+
+    fn rebalance(mut node: Box<Node>) -> Box<Node> {
+        Self::update_height(&mut node);
+        let balance = Self::balance_factor(&node);
+        if balance > 1 {
+            if Self::balance_factor(node.left.as_ref().unwrap()) < 0 {
+                let left = node.left.take().unwrap();
+                node.left = Some(Self::rotate_left(left));
             }
+            node = Self::rotate_right(node);
+        } else if balance < -1 {
+            if Self::balance_factor(node.right.as_ref().unwrap()) > 0 {
+                let right = node.right.take().unwrap();
+                node.right = Some(Self::rotate_right(right));
+            }
+            node = Self::rotate_left(node);
+        }
+        node
+    }
+
+    fn contains(&self, value: i32) -> bool {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            if value == node.value {
+                return true;
+            } else if value < node.value {
+                current = node.left.as_deref();
+            } else {
+                current = node.right.as_deref();
+            }
+        }
+        false
+    }
+
+    fn iter(&self) -> TreeIter<'_> {
+        TreeIter::new(&self.root)
+    }
+
+    fn remove(&mut self, value: i32) {
+        self.root = self
+            .root
+            .take()
+            .and_then(|node| Self::remove_recursive(node, value));
+    }
+
+    fn remove_recursive(mut node: Box<Node>, value: i32) -> Option<Box<Node>> {
+        if value < node.value {
+            node.left = node
+                .left
+                .take()
+                .and_then(|left| Self::remove_recursive(left, value));
+        } else if value > node.value {
+            node.right = node
+                .right
+                .take()
+                .and_then(|right| Self::remove_recursive(right, value));
+        } else if node.left.is_none() {
+            return node.right.take();
+        } else if node.right.is_none() {
+            return node.left.take();
+        } else {
+            // two children: splice in the in-order successor (leftmost node of the right
+            // subtree), then delete that successor from the right subtree
+            let successor_value = Self::min_value(&node.right);
+            node.value = successor_value;
+            node.right = node
+                .right
+                .take()
+                .and_then(|right| Self::remove_recursive(right, successor_value));
+        }
+        Some(Self::rebalance(node))
+    }
+
+    fn min_value(node: &Option<Box<Node>>) -> i32 {
+        let mut current = node.as_deref().expect("min_value called on empty subtree");
+        while let Some(left) = current.left.as_deref() {
+            current = left;
+        }
+        current.value
+    }
+}
+
+// Non-recursive in-order iterator: descends down `left` children pushing each node onto a
+// stack, then pops a node, yields it, and descends down its `right` subtree's `left` spine.
+struct TreeIter<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> TreeIter<'a> {
+    fn new(root: &'a Option<Box<Node>>) -> Self {
+        let mut iter = TreeIter { stack: Vec::new() };
+        iter.push_left_spine(root.as_deref());
+        iter
+    }
+
+    fn push_left_spine(&mut self, mut current: Option<&'a Node>) {
+        while let Some(node) = current {
+            self.stack.push(node);
+            current = node.left.as_deref();
         }
     }
 }
 
+impl<'a> Iterator for TreeIter<'a> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+        Some(node.value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,9 +254,115 @@ mod tests {
         tree.insert(6);
         tree.insert(4);
 
+        // AVL-balanced: 8's left subtree (3, 1, 6, 4) grows taller than its right (10),
+        // triggering a left-right rotation that promotes 6 to the root.
         assert_eq!(tree.root.is_some(), true);
-        assert_eq!(tree.root.as_ref().unwrap().value, 8);
+        assert_eq!(tree.root.as_ref().unwrap().value, 6);
         assert_eq!(tree.root.as_ref().unwrap().left.as_ref().unwrap().value, 3);
         assert_eq!(tree.root.as_ref().unwrap().left.as_ref().unwrap().left.as_ref().unwrap().value, 1);
+        assert_eq!(tree.root.as_ref().unwrap().right.as_ref().unwrap().value, 8);
+    }
+
+    #[test]
+    fn test_stays_balanced_on_sorted_insert() {
+        let mut tree = Tree::new();
+        for value in 1..=7 {
+            tree.insert(value);
+        }
+
+        fn height(node: &Option<Box<Node>>) -> i32 {
+            node.as_ref().map_or(0, |n| {
+                1 + height(&n.left).max(height(&n.right))
+            })
+        }
+
+        // A degenerate (unbalanced) insert of 1..=7 would have height 7; AVL keeps it at O(log n).
+        assert_eq!(height(&tree.root), 3);
+        assert_eq!(tree.iter().collect::<Vec<i32>>(), (1..=7).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut tree = Tree::new();
+        tree.insert(8);
+        tree.insert(10);
+        tree.insert(3);
+
+        assert!(tree.contains(8));
+        assert!(tree.contains(10));
+        assert!(tree.contains(3));
+        assert!(!tree.contains(5));
+    }
+
+    #[test]
+    fn test_iter_in_order() {
+        let mut tree = Tree::new();
+        tree.insert(8);
+        tree.insert(10);
+        tree.insert(3);
+        tree.insert(1);
+        tree.insert(6);
+        tree.insert(4);
+
+        let values: Vec<i32> = tree.iter().collect();
+        assert_eq!(values, vec![1, 3, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn test_remove_leaf() {
+        let mut tree = Tree::new();
+        tree.insert(8);
+        tree.insert(3);
+        tree.insert(1);
+
+        tree.remove(1);
+
+        assert!(!tree.contains(1));
+        assert_eq!(tree.iter().collect::<Vec<i32>>(), vec![3, 8]);
+    }
+
+    #[test]
+    fn test_remove_single_child() {
+        let mut tree = Tree::new();
+        // Inserting 5, 3, 7, 2 never triggers a rotation (every balance factor stays within
+        // [-1, 1]), so 3 keeps a single left child (2) with no right child at removal time.
+        tree.insert(5);
+        tree.insert(3);
+        tree.insert(7);
+        tree.insert(2);
+
+        tree.remove(3);
+
+        assert!(!tree.contains(3));
+        assert_eq!(tree.iter().collect::<Vec<i32>>(), vec![2, 5, 7]);
+    }
+
+    #[test]
+    fn test_remove_two_children() {
+        let mut tree = Tree::new();
+        tree.insert(8);
+        tree.insert(10);
+        tree.insert(3);
+        tree.insert(1);
+        tree.insert(6);
+        tree.insert(4);
+
+        tree.remove(3);
+
+        assert!(!tree.contains(3));
+        assert_eq!(tree.iter().collect::<Vec<i32>>(), vec![1, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn test_remove_root() {
+        let mut tree = Tree::new();
+        tree.insert(8);
+        tree.insert(10);
+        tree.insert(3);
+
+        tree.remove(8);
+
+        assert!(!tree.contains(8));
+        assert_eq!(tree.iter().collect::<Vec<i32>>(), vec![3, 10]);
     }
 }
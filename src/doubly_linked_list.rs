@@ -1,15 +1,15 @@
 use std::{
-    cell::RefCell,
+    cell::{Ref, RefCell, RefMut},
     rc::{Rc, Weak},
 };
 
-pub struct Node<T: Copy> {
+pub struct Node<T> {
     pub value: T,
     pub next: Option<NodePtr<T>>,
     pub prev: Option<Weak<RefCell<Node<T>>>>, // weak reference to avoid reference cycles
 }
 
-impl<T: Copy> Node<T> {
+impl<T> Node<T> {
     pub fn new(value: T) -> Self {
         Node {
             value,
@@ -20,7 +20,7 @@ impl<T: Copy> Node<T> {
 }
 
 // can call .into() on Node<T> to get Option<Rc<RefCell<Node<T>>>>
-impl<T: Copy> From<Node<T>> for Option<Rc<RefCell<Node<T>>>> {
+impl<T> From<Node<T>> for Option<Rc<RefCell<Node<T>>>> {
     fn from(node: Node<T>) -> Self {
         Some(Rc::new(RefCell::new(node)))
     }
@@ -29,19 +29,19 @@ impl<T: Copy> From<Node<T>> for Option<Rc<RefCell<Node<T>>>> {
 // Rc<RefCell<_>> allows us to have multiple mutable references to the same data
 type NodePtr<T> = Rc<RefCell<Node<T>>>;
 
-pub struct List<T: Copy> {
+pub struct List<T> {
     head: Option<NodePtr<T>>,
     tail: Option<NodePtr<T>>,
     count: usize,
 }
 
-impl<T: Copy> Default for List<T> {
+impl<T> Default for List<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: Copy> List<T> {
+impl<T> List<T> {
     pub fn new() -> Self {
         List {
             head: None,
@@ -54,6 +54,10 @@ impl<T: Copy> List<T> {
         self.count
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
     pub fn push_front(&mut self, value: T) {
         // mutable so we can assign previous node
         let mut node = Node::new(value);
@@ -79,24 +83,29 @@ impl<T: Copy> List<T> {
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
-        match &self.head.take() {
-            None => None,
-            Some(head) => {
-                let mut head = head.borrow_mut();
-                let next = head.next.take();
-                match next {
-                    None => {
-                        self.tail.take(); // also set tail to None
-                    }
-                    Some(next) => {
-                        next.borrow_mut().prev = None;
-                        self.head = Some(next);
-                    }
-                }
-                self.count -= 1;
-                Some(head.value)
+        let old_head = self.head.as_ref()?;
+        // A caller can be holding an extra strong Rc to this node (e.g. upgraded from
+        // `get_weak_tail`), in which case popping would have to either panic or silently
+        // corrupt the list. Check the strong count against what the list's own pointers
+        // account for *before* touching anything, so a blocked pop is a genuine no-op.
+        if Rc::strong_count(old_head) != self.structural_strong_count(old_head) {
+            return None;
+        }
+        let old_head = self.head.take().unwrap();
+        match old_head.borrow_mut().next.take() {
+            None => {
+                self.tail.take(); // also set tail to None
+            }
+            Some(next) => {
+                next.borrow_mut().prev = None;
+                self.head = Some(next);
             }
         }
+        self.count -= 1;
+        // The strong-count check above guarantees old_head is now the sole remaining Rc.
+        Rc::try_unwrap(old_head)
+            .ok()
+            .map(|cell| cell.into_inner().value)
     }
 
     pub fn push_back(&mut self, value: T) {
@@ -122,30 +131,43 @@ impl<T: Copy> List<T> {
     }
 
     pub fn pop_back(&mut self) -> Option<T> {
-        match &self.tail.take() {
-            None => None,
-            Some(tail) => {
-                let mut tail = tail.borrow_mut();
-                let prev = tail.prev.take();
-                match prev {
-                    None => {
-                        self.head.take();
-                    }
-                    Some(prev) => {
-                        let prev = prev.upgrade();
-                        if let Some(prev) = prev {
-                            prev.borrow_mut().next = None;
-                            self.tail = Some(prev);
-                        }
-                    }
-                };
-                self.count -= 1;
-                Some(tail.value)
+        let old_tail = self.tail.as_ref()?;
+        // See the matching comment in `pop_front`: verify before mutating anything.
+        if Rc::strong_count(old_tail) != self.structural_strong_count(old_tail) {
+            return None;
+        }
+        let old_tail = self.tail.take().unwrap();
+        match old_tail.borrow_mut().prev.take() {
+            None => {
+                self.head.take();
+            }
+            Some(prev) => {
+                if let Some(prev) = prev.upgrade() {
+                    prev.borrow_mut().next = None;
+                    self.tail = Some(prev);
+                }
             }
         }
+        self.count -= 1;
+        // The strong-count check above guarantees old_tail is now the sole remaining Rc.
+        Rc::try_unwrap(old_tail)
+            .ok()
+            .map(|cell| cell.into_inner().value)
     }
 
-    pub fn remove_node(&mut self, node: &mut NodePtr<T>) {
+    // Every node in the list has exactly one strong Rc pointing "down" into it from above
+    // (either `self.head`, if it has no predecessor, or its predecessor's `next`), plus one
+    // more from `self.tail` if it also happens to be the last node. That's the strong count
+    // we expect to see when nobody outside the list is holding an extra reference to `node`.
+    fn structural_strong_count(&self, node: &NodePtr<T>) -> usize {
+        let is_tail = self.tail.as_ref().is_some_and(|tail| Rc::ptr_eq(tail, node));
+        1 + usize::from(is_tail)
+    }
+
+    // Unlinks `node` from the list, dropping the strong references the list itself held to it
+    // (from `head`, `tail` or its predecessor's `next`); callers that hold their own `Rc` to
+    // `node` (e.g. to reinsert it elsewhere) keep a valid handle.
+    fn unlink(&mut self, node: &NodePtr<T>) {
         let (prev, next) = {
             let mut node = node.borrow_mut();
             let prev = match node.prev.take() {
@@ -175,8 +197,25 @@ impl<T: Copy> List<T> {
         }
     }
 
-    pub fn move_node_to_back(&mut self, mut node: NodePtr<T>) {
-        self.remove_node(&mut node);
+    /// Removes `node` from the list and, once it is the sole remaining strong `Rc`,
+    /// unwraps it to recover the owned element. `node` itself accounts for one strong
+    /// reference beyond the list's own structural pointers, so a caller holding no other
+    /// copy always succeeds; a caller holding an extra copy gets `None` with the list left
+    /// completely unchanged, same as `pop_front`/`pop_back`.
+    pub fn remove_node(&mut self, node: NodePtr<T>) -> Option<T> {
+        if Rc::strong_count(&node) != self.structural_strong_count(&node) + 1 {
+            return None;
+        }
+        self.unlink(&node);
+        self.count -= 1;
+        // The strong-count check above guarantees node is now the sole remaining Rc.
+        Rc::try_unwrap(node)
+            .ok()
+            .map(|cell| cell.into_inner().value)
+    }
+
+    pub fn move_node_to_back(&mut self, node: NodePtr<T>) {
+        self.unlink(&node);
         self.push_node_back(node);
     }
 
@@ -201,37 +240,75 @@ impl<T: Copy> List<T> {
         }
     }
 
-    pub fn iter(&self) -> ListIterator<T> {
+    pub fn iter(&self) -> ListIterator<T>
+    where
+        T: Clone,
+    {
         ListIterator {
             current: self.head.clone(),
             current_back: self.tail.clone(),
         }
     }
+
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
+        self.head
+            .as_ref()
+            .map(|head| Ref::map(head.borrow(), |node| &node.value))
+    }
+
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
+        self.tail
+            .as_ref()
+            .map(|tail| Ref::map(tail.borrow(), |node| &node.value))
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.head
+            .as_ref()
+            .map(|head| RefMut::map(head.borrow_mut(), |node| &mut node.value))
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.tail
+            .as_ref()
+            .map(|tail| RefMut::map(tail.borrow_mut(), |node| &mut node.value))
+    }
+
+    // Not a `std::iter::Iterator`: an `Item` of `RefMut<'_, T>` would need its lifetime tied to
+    // each node's own `RefCell` rather than to `&mut self`, which the trait can't express without
+    // GATs. `IterMut::next` keeps the currently-yielded node alive in its own field instead, so the
+    // guard it hands back stays valid for as long as the borrow of `self` that produced it.
+    pub fn iter_mut(&self) -> IterMut<T> {
+        IterMut {
+            next: self.head.clone(),
+            current: None,
+        }
+    }
 }
 
 // Help the compiler understand that we want to drop the entire list
-impl<T: Copy> Drop for List<T> {
+impl<T> Drop for List<T> {
     fn drop(&mut self) {
         while self.pop_back().is_some() {}
     }
 }
 
-pub struct ListIterator<T: Copy> {
+pub struct ListIterator<T> {
     current: Option<NodePtr<T>>,
     current_back: Option<NodePtr<T>>,
 }
 
-impl<T: Copy> DoubleEndedIterator for ListIterator<T> {
+impl<T: Clone> DoubleEndedIterator for ListIterator<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         match &self.current_back.take() {
             None => None,
             Some(current_back) => {
                 let current_back = current_back.borrow();
                 match &current_back.prev {
-                    None => Some(current_back.value),
+                    None => Some(current_back.value.clone()),
                     Some(prev) => {
                         self.current_back = prev.upgrade();
-                        Some(current_back.value)
+                        Some(current_back.value.clone())
                     }
                 }
             }
@@ -239,7 +316,7 @@ impl<T: Copy> DoubleEndedIterator for ListIterator<T> {
     }
 }
 
-impl<T: Copy> Iterator for ListIterator<T> {
+impl<T: Clone> Iterator for ListIterator<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -249,12 +326,68 @@ impl<T: Copy> Iterator for ListIterator<T> {
                 let current = current.borrow();
                 let next = current.next.clone();
                 self.current = next;
-                Some(current.value)
+                Some(current.value.clone())
             }
         }
     }
 }
 
+// Borrowing, mutable walk over the chain. See the comment on `List::iter_mut` for why this
+// isn't a `std::iter::Iterator`.
+pub struct IterMut<T> {
+    next: Option<NodePtr<T>>,
+    current: Option<NodePtr<T>>,
+}
+
+impl<T> IterMut<T> {
+    // Not `std::iter::Iterator::next`: see the comment on `List::iter_mut` for why this can't
+    // be a real trait impl.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<RefMut<'_, T>> {
+        let node = match self.next.take() {
+            Some(node) => node,
+            // Drop our hold on the last-yielded node so it can be popped/unwrapped elsewhere
+            // once iteration has finished.
+            None => {
+                self.current = None;
+                return None;
+            }
+        };
+        self.next = node.borrow().next.clone();
+        self.current = Some(node);
+        Some(RefMut::map(
+            self.current.as_ref().unwrap().borrow_mut(),
+            |node| &mut node.value,
+        ))
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+// Owning iterator that drains the list from both ends by popping its elements.
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,4 +469,133 @@ mod tests {
         assert_eq!(iter.next(), Some(3));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn works_into_iter_front_to_back() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn works_into_iter_from_both_ends() {
+        let mut list = List::new();
+        list.push_back(String::from("a"));
+        list.push_back(String::from("b"));
+        list.push_back(String::from("c"));
+        list.push_back(String::from("d"));
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(String::from("a")));
+        assert_eq!(iter.next_back(), Some(String::from("d")));
+        assert_eq!(iter.next(), Some(String::from("b")));
+        assert_eq!(iter.next_back(), Some(String::from("c")));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn works_peek_front_and_back() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(*list.peek_front().unwrap(), 1);
+        assert_eq!(*list.peek_back().unwrap(), 3);
+
+        *list.peek_front_mut().unwrap() = 10;
+        *list.peek_back_mut().unwrap() = 30;
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_back(), Some(30));
+    }
+
+    #[test]
+    fn works_peek_on_empty_list() {
+        let list: List<i32> = List::new();
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+    }
+
+    #[test]
+    fn works_iter_mut() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.iter_mut();
+        while let Some(mut value) = iter.next() {
+            *value *= 10;
+        }
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_front(), Some(20));
+        assert_eq!(list.pop_front(), Some(30));
+    }
+
+    #[test]
+    fn pop_is_a_true_no_op_when_a_second_strong_ref_is_alive() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        // Upgrading the weak tail gives the node a strong `Rc` beyond the list's own, so
+        // `pop_back`'s strong-count check refuses to pop it.
+        let extra_ref = list.get_weak_tail().unwrap().upgrade().unwrap();
+        assert_eq!(list.pop_back(), None);
+        // A blocked pop must leave the list completely untouched, not just avoid panicking.
+        assert_eq!(list.len(), 2);
+        assert_eq!(extra_ref.borrow().value, 2);
+        drop(extra_ref);
+
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn remove_node_removes_from_the_middle_head_and_tail() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+
+        let middle = list.head.as_ref().unwrap().borrow().next.clone().unwrap();
+        assert_eq!(list.remove_node(middle), Some(2));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().collect::<Vec<i32>>(), vec![1, 3]);
+
+        let head = list.head.as_ref().unwrap().clone();
+        assert_eq!(list.remove_node(head), Some(1));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.iter().collect::<Vec<i32>>(), vec![3]);
+
+        let tail = list.tail.as_ref().unwrap().clone();
+        assert_eq!(list.remove_node(tail), Some(3));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().collect::<Vec<i32>>(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn works_with_owned_non_copy_values() {
+        let mut list = List::new();
+        list.push_back(String::from("a"));
+        list.push_back(String::from("b"));
+        list.push_front(String::from("z"));
+
+        assert_eq!(list.pop_front(), Some(String::from("z")));
+        assert_eq!(list.pop_back(), Some(String::from("b")));
+        assert_eq!(list.pop_back(), Some(String::from("a")));
+        assert_eq!(list.pop_back(), None);
+    }
 }
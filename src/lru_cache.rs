@@ -4,13 +4,17 @@ use std::collections::HashMap;
 use std::hash::Hash;
 use std::rc::Weak;
 
-pub struct LRU<K: Copy + Eq + Hash, T: Copy> {
-    pub list: List<T>,
-    pub map: HashMap<K, Weak<RefCell<Node<T>>>>,
+type NodeWeak<K, T> = Weak<RefCell<Node<(K, T)>>>;
+
+pub struct LRU<K: Eq + Hash + Clone, T: Clone> {
+    // Each list node carries its key alongside its value so eviction can report which key
+    // fell off the list, letting `put` clear the matching (otherwise dangling) `map` entry.
+    pub list: List<(K, T)>,
+    pub map: HashMap<K, NodeWeak<K, T>>,
     pub capacity: usize,
 }
 
-impl<K: Copy + Eq + Hash, T: Copy> LRU<K, T> {
+impl<K: Eq + Hash + Clone, T: Clone> LRU<K, T> {
     pub fn new() -> Self {
         LRU::with_capacity(10)
     }
@@ -33,7 +37,7 @@ impl<K: Copy + Eq + Hash, T: Copy> LRU<K, T> {
         match ptr {
             None => None,
             Some(node) => {
-                let value = node.borrow().value;
+                let value = node.borrow().value.1.clone();
                 self.list.move_node_to_back(node);
                 Some(value)
             }
@@ -49,16 +53,18 @@ impl<K: Copy + Eq + Hash, T: Copy> LRU<K, T> {
         };
         match ptr {
             None => {
-                self.list.push_back(v);
+                self.list.push_back((k.clone(), v));
                 if let Some(tail) = self.list.get_weak_tail() {
                     self.map.insert(k, tail);
                 }
                 if self.list.len() > self.capacity {
-                    let head = self.list.pop_front();
+                    if let Some((evicted_key, _)) = self.list.pop_front() {
+                        self.map.remove(&evicted_key);
+                    }
                 }
             }
             Some(node) => {
-                node.borrow_mut().value = v;
+                node.borrow_mut().value.1 = v;
                 self.list.move_node_to_back(node);
             }
         }
@@ -96,11 +102,35 @@ mod tests {
         assert_eq!(lru.get(2), Some("bar"));
 
         let mut iter = lru.list.iter();
-        assert_eq!(iter.next_back(), Some("bar"));
-        assert_eq!(iter.next_back(), Some("fizz"));
-        assert_eq!(iter.next_back(), Some("bazz"));
-        assert_eq!(iter.next_back(), Some("buzz"));
-        assert_eq!(iter.next_back(), Some("foo"));
+        assert_eq!(iter.next_back(), Some((2, "bar")));
+        assert_eq!(iter.next_back(), Some((3, "fizz")));
+        assert_eq!(iter.next_back(), Some((5, "bazz")));
+        assert_eq!(iter.next_back(), Some((4, "buzz")));
+        assert_eq!(iter.next_back(), Some((1, "foo")));
         assert_eq!(iter.next_back(), None);
     }
+
+    #[test]
+    fn evicting_a_key_removes_it_from_the_map() {
+        let mut lru = LRU::with_capacity(2);
+        lru.put(1, "a");
+        lru.put(2, "b");
+        lru.put(3, "c"); // evicts key 1
+
+        assert_eq!(lru.get(1), None);
+        assert!(!lru.map.contains_key(&1));
+        assert_eq!(lru.map.len(), 2);
+    }
+
+    #[test]
+    fn works_with_owned_string_keys_and_values() {
+        let mut lru = LRU::with_capacity(2);
+        lru.put(String::from("a"), String::from("apple"));
+        lru.put(String::from("b"), String::from("banana"));
+        lru.put(String::from("c"), String::from("cherry")); // evicts "a"
+
+        assert_eq!(lru.get(String::from("a")), None);
+        assert_eq!(lru.get(String::from("b")), Some(String::from("banana")));
+        assert_eq!(lru.get(String::from("c")), Some(String::from("cherry")));
+    }
 }